@@ -65,15 +65,30 @@ use itertools::Itertools;
 /// the next element is the first element of the sequence (and vice versa when iterating in reverse).
 ///
 /// generally, you construct a `Zipper` by calling `FromIterator::collect` on an `Iterator`.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Zipper<T> {
     /// a stack for elements occurring later in the sequence.
     /// the first element of this stack is the one currently focused.
     pub(crate) forward: VecDeque<T>,
     /// a stack for elements occurring earlier in the sequence
     pub(crate) backward: VecDeque<T>,
+    /// the direction the cursor is currently travelling in. a `seek` sets this so that subsequent
+    /// `advance` calls continue in the seek's direction until the caller changes it. it is purely a
+    /// transient cursor and is deliberately excluded from equality.
+    pub(crate) direction: SequenceDirection,
 }
 
+/// equality compares the ring and focus only -- the transient `direction` cursor is not part of a
+/// `Zipper`'s identity, so two zippers holding the same elements in the same orientation compare
+/// equal regardless of which way they were last seeking.
+impl<T: PartialEq> PartialEq for Zipper<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.forward == other.forward && self.backward == other.backward
+    }
+}
+
+impl<T: Eq> Eq for Zipper<T> {}
+
 impl<T> Display for Zipper<T>
 where
     T: Display,
@@ -90,6 +105,19 @@ pub enum SequenceDirection {
     Reverse,
 }
 
+/// a repositioning request for [`Zipper::seek`], modeled on the iterator seek modes of an embedded
+/// database like RocksDB. it unifies the focus-moving operations behind one explicit API.
+pub enum ZipperSeek<'a, T> {
+    /// reposition to the start of the original sequence, like [`Zipper::reset_start`].
+    Start,
+    /// reposition to the end of the original sequence, like [`Zipper::reset_end`].
+    End,
+    /// step forward from the current focus until the predicate holds or a full revolution elapses.
+    FromForward(Box<dyn FnMut(&T) -> bool + 'a>),
+    /// step backward from the current focus until the predicate holds or a full revolution elapses.
+    FromReverse(Box<dyn FnMut(&T) -> bool + 'a>),
+}
+
 fn push_and_yield<T>(n: &mut VecDeque<T>, t: T) -> &mut VecDeque<T> {
     n.push_front(t);
     n
@@ -114,6 +142,7 @@ impl<T> Zipper<T> {
         Self {
             forward: VecDeque::new(),
             backward: VecDeque::new(),
+            direction: SequenceDirection::Original,
         }
     }
 
@@ -175,6 +204,68 @@ impl<T> Zipper<T> {
         self
     }
 
+    /// reposition the focus in a single call, unifying [`Zipper::reset_start`], [`Zipper::reset_end`],
+    /// [`Zipper::refocus`] and [`Zipper::refocus_backwards`] behind one explicit, database-style API.
+    ///
+    /// the seek also updates the `Zipper`'s persistent direction cursor so that subsequent
+    /// [`Zipper::advance`] calls continue in the seek's direction until the caller changes it.
+    ///
+    /// returns `Some(focus)` when the focus lands on a matching element and `None` when a
+    /// `FromForward`/`FromReverse` search completes a full revolution without matching. this makes a
+    /// failed search distinguishable from one that happens to land back on the starting focus.
+    pub fn seek(&mut self, mode: ZipperSeek<'_, T>) -> Option<&T> {
+        match mode {
+            ZipperSeek::Start => {
+                self.direction = SequenceDirection::Original;
+                self.reset_start();
+            }
+            ZipperSeek::End => {
+                self.direction = SequenceDirection::Reverse;
+                self.reset_end();
+            }
+            ZipperSeek::FromForward(mut p) => {
+                self.direction = SequenceDirection::Original;
+                if !self.seek_matching(SequenceDirection::Original, &mut p) {
+                    return None;
+                }
+            }
+            ZipperSeek::FromReverse(mut p) => {
+                self.direction = SequenceDirection::Reverse;
+                if !self.seek_matching(SequenceDirection::Reverse, &mut p) {
+                    return None;
+                }
+            }
+        }
+
+        self.focus()
+    }
+
+    /// step in the given direction until the predicate holds, giving up after a full revolution.
+    /// reports whether a matching element was found.
+    fn seek_matching(&mut self, dir: SequenceDirection, p: &mut impl FnMut(&T) -> bool) -> bool {
+        for _ in 0..self.size() {
+            if let Some(t) = self.step(dir).focus()
+                && p(t)
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// advance the focus one step in the direction of the persistent cursor set by the last
+    /// [`Zipper::seek`] (defaulting to `Original`). this gives callers a stateful cursor that keeps
+    /// moving the way it last sought, like a database iterator.
+    pub fn advance(&mut self) -> &mut Self {
+        self.step(self.direction)
+    }
+
+    /// the direction the persistent cursor is currently travelling in.
+    pub fn direction(&self) -> SequenceDirection {
+        self.direction
+    }
+
     /// reset the focused element to the start of the original sequence
     pub fn reset_start(&mut self) -> &mut Self {
         reset(&mut self.forward, &mut self.backward);
@@ -248,14 +339,83 @@ impl<T> Zipper<T> {
         self
     }
 
+    /// insert `elem` immediately after the focus without moving the focus. on an empty `Zipper` the
+    /// inserted element simply becomes the focus.
+    pub fn insert_after(&mut self, elem: T) -> &mut Self {
+        let at = 1.min(self.forward.len());
+        self.forward.insert(at, elem);
+        self
+    }
+
+    /// insert `elem` immediately before the focus, pushing it onto the back stack. the focus is
+    /// unchanged. on an empty `Zipper` the element becomes the focus, preserving the invariant that
+    /// `forward` is non-empty whenever the `Zipper` is.
+    pub fn insert_before(&mut self, elem: T) -> &mut Self {
+        if self.forward.is_empty() {
+            self.forward.push_front(elem);
+        } else {
+            self.backward.push_front(elem);
+        }
+        self
+    }
+
+    /// remove the focused element and move the focus to the next element forward, refilling the
+    /// forward stack from the back stack when the last physical element of it is removed. returns the
+    /// removed element, or `None` if the `Zipper` was empty.
+    pub fn remove_focus(&mut self) -> Option<T> {
+        self.take_current_focus()
+    }
+
+    /// insert a whole run of elements immediately after the focus, preserving their order. the focus
+    /// is unchanged.
+    pub fn splice(&mut self, iter: impl IntoIterator<Item = T>) -> &mut Self {
+        let base = 1.min(self.forward.len());
+        for (offset, elem) in iter.into_iter().enumerate() {
+            self.forward.insert(base + offset, elem);
+        }
+        self
+    }
+
+    /// transform every element of the `Zipper` with `f`, preserving the ring and the current focus.
+    /// because the forward/backward split is kept intact, the focus stays on the same logical element.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Zipper<U> {
+        Zipper {
+            forward: self.forward.into_iter().map(&mut f).collect(),
+            backward: self.backward.into_iter().map(&mut f).collect(),
+            direction: self.direction,
+        }
+    }
+
+    /// transform and filter the `Zipper` with `f`, keeping only the elements that map to `Some`.
+    /// the focus is recomputed by walking the ring forward from the current focus and landing on the
+    /// first retained element at or after it (wrapping around if everything after the focus was
+    /// dropped). if nothing survives, the result is an empty `Zipper`.
+    pub fn filter_map<U>(self, mut f: impl FnMut(T) -> Option<U>) -> Zipper<U> {
+        let direction = self.direction;
+        // the ring in logical order starting at the focus: the forward stack followed by the
+        // backward stack read from its far end back towards the focus.
+        let forward = self
+            .forward
+            .into_iter()
+            .chain(self.backward.into_iter().rev())
+            .filter_map(&mut f)
+            .collect();
+
+        Zipper {
+            forward,
+            backward: VecDeque::new(),
+            direction,
+        }
+    }
+
     /// yield an `Iterator` that iterates in the order imposed by the original sequence but starting at the currently
     /// focused element. the element following the last element of the original sequence is the first element of the
     /// sequence.
     pub fn iter(&'_ self) -> ZipperIter<'_, T> {
         ZipperIter {
             zipper: self,
-            count: self.size(),
-            cursor: 0,
+            front: 0,
+            back: self.size(),
             dir: SequenceDirection::Original,
         }
     }
@@ -266,8 +426,8 @@ impl<T> Zipper<T> {
     pub fn reverse_iter(&'_ self) -> ZipperIter<'_, T> {
         ZipperIter {
             zipper: self,
-            count: self.size(),
-            cursor: 0,
+            front: 0,
+            back: self.size(),
             dir: SequenceDirection::Reverse,
         }
     }
@@ -298,19 +458,58 @@ impl<T> FromIterator<T> for Zipper<T> {
     }
 }
 
+/// generate a lockstep zipping function over a fixed number of input zippers.
+///
+/// each generated function walks `n = min(sizes)` steps starting at every input's current focus,
+/// pairs the `i`th element of each input for `i` in `0..n`, and collects the tuples into a fresh
+/// ring whose focus is the tuple of the input foci. stepping the result therefore corresponds to
+/// stepping each input forward together.
+macro_rules! zipper_zip {
+    ($name:ident, $($t:ident : $z:ident),+) => {
+        /// zip several zippers in lockstep from their current foci into a `Zipper` of tuples.
+        /// the inputs are read through `ith`, so the elements are cloned into the combined ring.
+        pub fn $name<$($t),+>($($z: &Zipper<$t>),+) -> Zipper<($($t,)+)>
+        where
+            $($t: Clone),+
+        {
+            let n = [$($z.size()),+].into_iter().min().unwrap_or(0);
+            (0..n)
+                .map(|i| ($($z.ith(i as isize).unwrap().clone(),)+))
+                .collect()
+        }
+    };
+}
+
+zipper_zip!(zip2, A: za, B: zb);
+zipper_zip!(zip3, A: za, B: zb, C: zc);
+zipper_zip!(zip4, A: za, B: zb, C: zc, D: zd);
+
 /// an `Iterator` that yields the elements of the sequence the `Zipper` was opened over, starting with the currently
 /// focused element and continuing until all elements have been yielded. sequence ordering is preserved.
 pub struct ZipperIter<'a, T> {
     /// sequence state
     zipper: &'a Zipper<T>,
-    /// number of elements in the sequence
-    count: usize,
-    /// keep track of which items in the sequence we've already yielded -- otherwise we'll spin indefinitely.
-    cursor: isize,
-    /// this iterator can go forwards or backwards
+    /// cursor tracking the next element to yield from the front of the remaining range.
+    front: usize,
+    /// cursor tracking one past the next element to yield from the back of the remaining range.
+    /// the iterator is exhausted once `front >= back`.
+    back: usize,
+    /// the orientation the iterator started in -- `Original` walks the sequence from the focus,
+    /// `Reverse` walks it backwards from the focus. either way the iterator is double-ended.
     dir: SequenceDirection,
 }
 
+impl<'a, T> ZipperIter<'a, T> {
+    /// resolve a logical offset (counted from the focus in this iterator's orientation) to an
+    /// element of the underlying ring.
+    fn at(&self, i: usize) -> Option<&'a T> {
+        match self.dir {
+            SequenceDirection::Original => self.zipper.ith(i as isize),
+            SequenceDirection::Reverse => self.zipper.ith(-(i as isize)),
+        }
+    }
+}
+
 impl<'a, T> Iterator for ZipperIter<'a, T>
 where
     T: 'a,
@@ -318,17 +517,41 @@ where
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.cursor <= -1 * self.count as isize || self.cursor >= self.count as isize {
+        if self.front >= self.back {
             return None;
         }
 
-        let i = self.cursor;
-        self.cursor += match &self.dir {
-            SequenceDirection::Original => 1,
-            SequenceDirection::Reverse => -1,
-        };
+        let i = self.front;
+        self.front += 1;
+        self.at(i)
+    }
 
-        self.zipper.ith(i)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.len();
+        (n, Some(n))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ZipperIter<'a, T>
+where
+    T: 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.at(self.back)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ZipperIter<'a, T>
+where
+    T: 'a,
+{
+    fn len(&self) -> usize {
+        self.back - self.front
     }
 }
 
@@ -501,6 +724,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn iterator_is_double_ended_and_exact_sized() {
+        let mut zipper = (0..10).into_iter().collect::<Zipper<_>>();
+        zipper.refocus(|t| *t == 5);
+
+        let mut it = zipper.iter();
+        assert_eq!(it.len(), 10, "a fresh iterator should report the full length");
+        assert_eq!(it.size_hint(), (10, Some(10)), "size_hint should be exact");
+
+        assert_eq!(it.next().copied(), Some(5), "next should yield from the focus");
+        assert_eq!(
+            it.next_back().copied(),
+            Some(4),
+            "next_back should yield from the end of the sequence"
+        );
+        assert_eq!(it.len(), 8, "consuming from both ends should shrink the length");
+
+        let v = zipper.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(
+            &v,
+            &[4, 3, 2, 1, 0, 9, 8, 7, 6, 5],
+            "rev should walk the forward iteration order back to front"
+        );
+    }
+
+    #[test]
+    fn editing_api_inserts_removes_and_splices_around_focus() {
+        let mut zipper = (0..4).into_iter().collect::<Zipper<_>>();
+        zipper.refocus(|t| *t == 1);
+
+        zipper.insert_after(10);
+        assert_eq!(
+            zipper.focus().copied(),
+            Some(1),
+            "insert_after should leave the focus unchanged"
+        );
+        assert_eq!(
+            zipper.iter().copied().collect::<Vec<_>>(),
+            vec![1, 10, 2, 3, 0],
+            "insert_after should place the element immediately after the focus"
+        );
+
+        zipper.insert_before(20);
+        assert_eq!(
+            zipper.iter().copied().collect::<Vec<_>>(),
+            vec![1, 10, 2, 3, 0, 20],
+            "insert_before should place the element immediately before the focus in the ring"
+        );
+
+        zipper.splice([30, 31]);
+        assert_eq!(
+            zipper.iter().copied().collect::<Vec<_>>(),
+            vec![1, 30, 31, 10, 2, 3, 0, 20],
+            "splice should insert a run after the focus in order"
+        );
+
+        assert_eq!(zipper.remove_focus(), Some(1), "remove_focus should return the focused element");
+        assert_eq!(
+            zipper.focus().copied(),
+            Some(30),
+            "remove_focus should move the focus to the next element forward"
+        );
+    }
+
+    #[test]
+    fn map_preserves_focus_and_filter_map_recomputes_it() {
+        let mut zipper = (0..10).into_iter().collect::<Zipper<_>>();
+        zipper.refocus(|t| *t == 5);
+
+        let doubled = zipper.clone().map(|t| t * 2);
+        assert_eq!(
+            doubled.focus().copied(),
+            Some(10),
+            "map should keep the focus on the same logical element"
+        );
+        assert_eq!(
+            doubled.iter().copied().collect::<Vec<_>>(),
+            vec![10, 12, 14, 16, 18, 0, 2, 4, 6, 8],
+            "map should preserve the ring order"
+        );
+
+        let evens = zipper.clone().filter_map(|t| (t % 2 == 0).then_some(t));
+        assert_eq!(
+            evens.iter().copied().collect::<Vec<_>>(),
+            vec![6, 8, 0, 2, 4],
+            "filter_map should retain matching elements in ring order from the focus"
+        );
+        assert_eq!(
+            evens.focus().copied(),
+            Some(6),
+            "filter_map should land the focus on the first retained element at or after the old focus"
+        );
+
+        let none = zipper.filter_map(|t| (t > 100).then_some(t));
+        assert_eq!(
+            none.size(),
+            0,
+            "filter_map should yield an empty zipper when nothing survives"
+        );
+    }
+
+    #[test]
+    fn seek_repositions_and_remembers_direction() {
+        let mut zipper = (0..10).into_iter().collect::<Zipper<_>>();
+
+        assert_eq!(
+            zipper.seek(ZipperSeek::End).copied(),
+            Some(9),
+            "seeking to the end should focus the last element"
+        );
+        assert_eq!(
+            zipper.direction(),
+            SequenceDirection::Reverse,
+            "seeking to the end should leave the cursor travelling in reverse"
+        );
+        zipper.advance();
+        assert_eq!(
+            zipper.focus().copied(),
+            Some(8),
+            "advancing after an End seek should continue backwards"
+        );
+
+        assert_eq!(
+            zipper.seek(ZipperSeek::FromForward(Box::new(|t| *t == 3))).copied(),
+            Some(3),
+            "a forward seek should land on the first matching element"
+        );
+        zipper.advance();
+        assert_eq!(
+            zipper.focus().copied(),
+            Some(4),
+            "advancing after a forward seek should continue forwards"
+        );
+
+        assert_eq!(
+            zipper.seek(ZipperSeek::FromForward(Box::new(|t| *t == 42))),
+            None,
+            "a forward seek with no match should report failure"
+        );
+    }
+
+    #[test]
+    fn zip2_pairs_zippers_in_lockstep() {
+        let mut za = (0..5).into_iter().collect::<Zipper<_>>();
+        za.refocus(|t| *t == 2);
+        let zb = ('a'..='e').collect::<Zipper<_>>();
+
+        let mut zipped = zip2(&za, &zb);
+        assert_eq!(
+            zipped.focus().copied(),
+            Some((2, 'a')),
+            "the combined focus should pair the two input foci"
+        );
+
+        let v = zipped.iter().copied().collect::<Vec<_>>();
+        assert_eq!(
+            &v,
+            &[(2, 'a'), (3, 'b'), (4, 'c'), (0, 'd'), (1, 'e')],
+            "zipping should advance both inputs forward from their foci together"
+        );
+
+        zipped.step_forwards();
+        assert_eq!(
+            zipped.focus().copied(),
+            Some((3, 'b')),
+            "stepping the combined zipper should step both inputs together"
+        );
+    }
+
     #[test]
     fn refocus_forward_and_backwards_is_equivalent() {
         let mut zipper1 = (0..10).into_iter().collect::<Zipper<_>>();